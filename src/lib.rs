@@ -25,16 +25,285 @@ use winrt::Error as WError;
 use std::{
     error::Error as StdError,
     fmt::{self, Display, Formatter},
+    sync::{Mutex, OnceLock},
 };
 
-trait Platform {
+trait Platform: Sized {
+    type Handle: PlatformHandle;
+
     fn setup() -> Self;
-    fn notify(msg_title: &str, msg_body: &str) -> Result<(), ErrorRepr>;
+    fn notify(&self, notification: &Notification) -> Result<Self::Handle, ErrorRepr>;
+    fn notify_with_actions(
+        &self,
+        notification: &Notification,
+        actions: &[Action],
+    ) -> Result<ActionResponse, ErrorRepr>;
+}
+
+/// Per-platform handle to an already-shown notification.
+///
+/// Users interact with this through [`NotificationHandle`] rather than
+/// naming a concrete implementor directly.
+trait PlatformHandle {
+    fn update(&mut self, title: &str, body: &str) -> Result<(), ErrorRepr>;
+    fn close(self) -> Result<(), ErrorRepr>;
+}
+
+/// A handle to a notification that's currently on screen.
+///
+/// Returned by [`NotificationBuilder::show`]; lets callers update the
+/// notification's text or dismiss it programmatically, which is handy for
+/// progress-style notifications.
+pub struct NotificationHandle(<CurrPlatform as Platform>::Handle);
+
+impl NotificationHandle {
+    /// Replace the notification's title and body in place.
+    ///
+    /// On macOS the shown notification is left as it is and a new one with
+    /// the given title and body is sent instead.
+    pub fn update(&mut self, title: &str, body: &str) -> Result<(), Error> {
+        self.0.update(title, body).map_err(Error)
+    }
+
+    /// Close the notification.
+    ///
+    /// On macOS this does nothing; the notification stays on screen.
+    pub fn close(self) -> Result<(), Error> {
+        self.0.close().map_err(Error)
+    }
+}
+
+/// An action button attached to a notification.
+#[derive(Debug, Clone)]
+pub struct Action {
+    id: String,
+    label: String,
+}
+
+impl Action {
+    pub fn new(id: &str, label: &str) -> Self {
+        Action {
+            id: id.to_string(),
+            label: label.to_string(),
+        }
+    }
+}
+
+/// How the user responded to a notification shown with [`NotificationBuilder::action`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ActionResponse {
+    /// The action with this id was clicked.
+    Action(String),
+    /// The notification was dismissed without picking an action.
+    Dismissed,
+    /// The notification expired before the user responded.
+    TimedOut,
+    /// This platform can't show action buttons or report how the user
+    /// responded; the notification was shown without them.
+    Unsupported,
+}
+
+/// Identity under which notifications are sent, shared by all platforms.
+#[derive(Debug, Clone, Default)]
+struct Config {
+    application: Option<String>,
+}
+
+fn config() -> &'static Mutex<Config> {
+    static CONFIG: OnceLock<Mutex<Config>> = OnceLock::new();
+    CONFIG.get_or_init(|| Mutex::new(Config::default()))
+}
+
+/// Set the identity that notifications are sent under: a bundle
+/// identifier on macOS, an AppUserModelID on Windows, or an application
+/// name on Linux.
+///
+/// This affects every notification shown afterwards, including through
+/// [`notify`].
+pub fn set_application(application: &str) {
+    config().lock().unwrap().application = Some(application.to_string());
+}
+
+/// The urgency/priority of a notification.
+///
+/// Not every platform distinguishes between these; unsupported levels are
+/// simply ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Urgency {
+    Low,
+    #[default]
+    Normal,
+    Critical,
+}
+
+/// How long a notification should stay on screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Timeout {
+    /// Let the platform pick its own default.
+    #[default]
+    Default,
+    /// Keep the notification around until the user dismisses it.
+    Never,
+    /// Expire the notification after the given number of milliseconds.
+    Milliseconds(u32),
+}
+
+/// The sound to play when a notification is shown.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Sound {
+    /// Let the platform pick its own default sound.
+    #[default]
+    Default,
+    /// Show the notification without playing a sound.
+    Silent,
+    /// Play the named platform sound (e.g. a sound file stem on Linux, or
+    /// the name of a system sound on macOS).
+    Named(String),
+}
+
+/// A notification to be shown to the user.
+///
+/// Build one with [`NotificationBuilder`] rather than constructing it
+/// directly.
+#[derive(Debug, Clone, Default)]
+pub struct Notification {
+    title: String,
+    body: String,
+    icon: Option<String>,
+    sound: Sound,
+    timeout: Timeout,
+    urgency: Urgency,
+}
+
+/// Builder for a [`Notification`].
+///
+/// Fields left unset fall back to each platform's own default, so it's
+/// always safe to only set the ones you care about.
+///
+/// Not every field is honoured on every platform. macOS ignores the icon,
+/// timeout and urgency, and sends no sound name for [`Sound::Default`] or
+/// [`Sound::Silent`]. Windows ignores the sound and urgency, and only picks
+/// between a long toast ([`Timeout::Never`]) and a short one
+/// ([`Timeout::Milliseconds`], whatever the number).
+///
+/// ```rust
+/// notifica::NotificationBuilder::new("Hello", "World! 🌍")
+///     .urgency(notifica::Urgency::Critical)
+///     .show();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct NotificationBuilder {
+    notification: Notification,
+    actions: Vec<Action>,
+}
+
+impl NotificationBuilder {
+    pub fn new(title: &str, body: &str) -> Self {
+        NotificationBuilder {
+            notification: Notification {
+                title: title.to_string(),
+                body: body.to_string(),
+                ..Notification::default()
+            },
+            actions: Vec::new(),
+        }
+    }
+
+    /// Attach an action button, identified by `id`, with the given label.
+    ///
+    /// Use [`Self::show_with_actions`] instead of [`Self::show`] to find out
+    /// which one the user clicked.
+    pub fn action(mut self, id: &str, label: &str) -> Self {
+        self.actions.push(Action::new(id, label));
+        self
+    }
+
+    /// Set the path to an icon/image to show alongside the notification.
+    pub fn icon(mut self, path: &str) -> Self {
+        self.notification.icon = Some(path.to_string());
+        self
+    }
+
+    /// Set the sound played when the notification is shown.
+    pub fn sound(mut self, sound: Sound) -> Self {
+        self.notification.sound = sound;
+        self
+    }
+
+    /// Set how long the notification should stay visible.
+    pub fn timeout(mut self, timeout: Timeout) -> Self {
+        self.notification.timeout = timeout;
+        self
+    }
+
+    /// Set the notification's urgency level.
+    pub fn urgency(mut self, urgency: Urgency) -> Self {
+        self.notification.urgency = urgency;
+        self
+    }
+
+    /// Show the notification, using whichever backend is appropriate for
+    /// the current platform.
+    pub fn show(self) -> Result<NotificationHandle, Error> {
+        let platform = CurrPlatform::setup();
+        platform
+            .notify(&self.notification)
+            .map(NotificationHandle)
+            .map_err(Error)
+    }
+
+    /// Show the notification and block until the user picks an action,
+    /// dismisses it, or it times out.
+    ///
+    /// Not every platform supports action buttons: macOS has no way to
+    /// attach them or observe a response with the bundled
+    /// `mac_notification_sys` version, so there this returns
+    /// [`ActionResponse::Unsupported`] immediately instead of blocking.
+    pub fn show_with_actions(self) -> Result<ActionResponse, Error> {
+        let platform = CurrPlatform::setup();
+        platform
+            .notify_with_actions(&self.notification, &self.actions)
+            .map_err(Error)
+    }
+}
+
+/// A coarse classification of an [`Error`], so callers can react to
+/// specific failure modes (e.g. a user-denied permission prompt) without
+/// matching on [`Display`](std::fmt::Display) output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The OS denied permission to show notifications for this application.
+    PermissionDenied,
+    /// Some other, backend-specific failure.
+    Backend,
+}
+
+/// Backends don't expose a typed permission-denied error, so fall back to
+/// recognizing the OS's own wording for it.
+fn classify_message(message: &str) -> ErrorKind {
+    let message = message.to_lowercase();
+    if message.contains("denied") || message.contains("not allowed") {
+        ErrorKind::PermissionDenied
+    } else {
+        ErrorKind::Backend
+    }
 }
 
 #[derive(Debug)]
 pub struct Error(ErrorRepr);
 
+impl Error {
+    /// A coarse classification of this error.
+    pub fn kind(&self) -> ErrorKind {
+        self.0.kind()
+    }
+
+    /// Shorthand for `self.kind() == ErrorKind::PermissionDenied`.
+    pub fn is_permission_denied(&self) -> bool {
+        self.kind() == ErrorKind::PermissionDenied
+    }
+}
+
 #[derive(Debug)]
 enum ErrorRepr {
     #[cfg(target_os = "linux")]
@@ -48,6 +317,19 @@ enum ErrorRepr {
 impl StdError for Error {}
 impl StdError for ErrorRepr {}
 
+impl ErrorRepr {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            #[cfg(target_os = "linux")]
+            ErrorRepr::Linux(e) => classify_message(&e.to_string()),
+            #[cfg(target_os = "macos")]
+            ErrorRepr::MacOs(e) => classify_message(&e.to_string()),
+            #[cfg(target_os = "windows")]
+            ErrorRepr::Windows(e) => classify_message(&format!("{:?}", e)),
+        }
+    }
+}
+
 #[cfg(target_os = "macos")]
 #[derive(Debug)]
 enum MacOsError {
@@ -127,75 +409,380 @@ impl From<WError> for ErrorRepr {
 }
 
 #[cfg(target_os = "windows")]
-struct Windows;
+struct Windows {
+    app_user_model_id: Option<String>,
+}
+
+/// Handle to a toast shown via [`Windows`]; keeps the XML `notify()`
+/// originally built (icon, duration, and all) so that `update()` can patch
+/// just its text nodes and re-show it, since WinRT toasts are otherwise
+/// immutable once shown.
+#[cfg(target_os = "windows")]
+struct WindowsHandle {
+    notifier: winrt::windows::ui::notifications::ToastNotifier,
+    toast: winrt::windows::ui::notifications::ToastNotification,
+    toast_xml: winrt::windows::data::xml::dom::XmlDocument,
+}
+
+#[cfg(target_os = "windows")]
+impl PlatformHandle for WindowsHandle {
+    fn update(&mut self, title: &str, body: &str) -> Result<(), ErrorRepr> {
+        use winrt::windows::data::xml::dom::*;
+        use winrt::windows::ui::notifications::*;
+        use winrt::*;
+
+        let toast_text_elements =
+            self.toast_xml.get_elements_by_tag_name(&FastHString::new("text"))?.unwrap();
+        for (index, text) in [title, body].iter().enumerate() {
+            toast_text_elements
+                .item(index as u32)?.unwrap()
+                .first_child()?.unwrap()
+                .query_interface::<IXmlText>().unwrap()
+                .set_text(&FastHString::from(*text))?;
+        }
+
+        self.notifier.hide(&self.toast)?;
+        self.toast = ToastNotification::create_toast_notification(&*self.toast_xml)?;
+        self.notifier.show(&self.toast)?;
+        Ok(())
+    }
+
+    fn close(self) -> Result<(), ErrorRepr> {
+        self.notifier.hide(&self.toast)?;
+        Ok(())
+    }
+}
 
 #[cfg(target_os = "windows")]
 impl Platform for Windows {
+    type Handle = WindowsHandle;
+
     fn setup() -> Self {
-        Windows
+        Windows {
+            app_user_model_id: config().lock().unwrap().application.clone(),
+        }
+    }
+
+    fn notify(&self, notification: &Notification) -> Result<WindowsHandle, ErrorRepr> {
+        use winrt::windows::ui::notifications::*;
+        let toast_xml = self.build(notification)?;
+        let toast = ToastNotification::create_toast_notification(&*toast_xml)?;
+        let notifier = self.notifier()?;
+        notifier.show(&toast)?;
+        Ok(WindowsHandle {
+            notifier,
+            toast,
+            toast_xml,
+        })
     }
 
-    fn notify(msg_title: &str, msg_body: &str) -> Result<(), ErrorRepr> {
+    fn notify_with_actions(
+        &self,
+        notification: &Notification,
+        actions: &[Action],
+    ) -> Result<ActionResponse, ErrorRepr> {
         use winrt::windows::data::xml::dom::*;
         use winrt::windows::ui::notifications::*;
         use winrt::*;
-        let toast_xml =
-            ToastNotificationManager::get_template_content(ToastTemplateType::ToastText02)?.unwrap();
+
+        let toast_xml = self.build(notification)?;
+
+        if !actions.is_empty() {
+            let toast_element = toast_xml
+                .get_elements_by_tag_name(&FastHString::new("toast"))?.unwrap()
+                .item(0)?.unwrap()
+                .query_interface::<IXmlElement>().unwrap();
+            let actions_element = toast_xml.create_element(&FastHString::new("actions"))?.unwrap();
+            for action in actions {
+                let action_element = toast_xml.create_element(&FastHString::new("action"))?.unwrap();
+                action_element.set_attribute(
+                    &FastHString::new("content"),
+                    &FastHString::from(action.label.as_str()),
+                )?;
+                action_element.set_attribute(
+                    &FastHString::new("arguments"),
+                    &FastHString::from(action.id.as_str()),
+                )?;
+                action_element.set_attribute(
+                    &FastHString::new("activationType"),
+                    &FastHString::new("foreground"),
+                )?;
+                actions_element.append_child(&*action_element.query_interface::<IXmlNode>().unwrap())?;
+            }
+            toast_element.append_child(&*actions_element.query_interface::<IXmlNode>().unwrap())?;
+        }
+
+        let toast = ToastNotification::create_toast_notification(&*toast_xml)?;
+        let (sender, receiver) = std::sync::mpsc::channel();
+
+        let activated_sender = sender.clone();
+        toast.add_activated(TypedEventHandler::new(move |_, args| {
+            let arguments = args
+                .query_interface::<ToastActivatedEventArgs>()
+                .and_then(|args| args.get_arguments())
+                .map(|s| s.to_string())
+                .unwrap_or_default();
+            let _ = activated_sender.send(ActionResponse::Action(arguments));
+            Ok(())
+        }))?;
+        let dismissed_sender = sender.clone();
+        toast.add_dismissed(TypedEventHandler::new(move |_, _| {
+            let _ = dismissed_sender.send(ActionResponse::Dismissed);
+            Ok(())
+        }))?;
+        toast.add_failed(TypedEventHandler::new(move |_, _| {
+            let _ = sender.send(ActionResponse::TimedOut);
+            Ok(())
+        }))?;
+
+        self.notifier()?.show(&*toast)?;
+        Ok(receiver.recv().unwrap_or(ActionResponse::Dismissed))
+    }
+}
+
+#[cfg(target_os = "windows")]
+impl Windows {
+    fn app_user_model_id(&self) -> &str {
+        self.app_user_model_id.as_deref().unwrap_or(
+            "{1AC14E77-02E7-4E5D-B744-2EB1AE5198B7}\\WindowsPowerShell\\v1.0\\powershell.exe",
+        )
+    }
+
+    fn notifier(&self) -> Result<winrt::windows::ui::notifications::ToastNotifier, ErrorRepr> {
+        use winrt::windows::ui::notifications::*;
+        use winrt::FastHString;
+        Ok(
+            ToastNotificationManager::create_toast_notifier_with_id(&FastHString::new(
+                self.app_user_model_id(),
+            ))?
+            .unwrap(),
+        )
+    }
+
+    fn build(
+        &self,
+        notification: &Notification,
+    ) -> Result<winrt::windows::data::xml::dom::XmlDocument, ErrorRepr> {
+        use winrt::windows::data::xml::dom::*;
+        use winrt::windows::ui::notifications::*;
+        use winrt::*;
+        let template = if notification.icon.is_some() {
+            ToastTemplateType::ToastImageAndText02
+        } else {
+            ToastTemplateType::ToastText02
+        };
+        let toast_xml = ToastNotificationManager::get_template_content(template)?.unwrap();
+
         let toast_text_elements =
             toast_xml.get_elements_by_tag_name(&FastHString::new("text"))?.unwrap();
-
         toast_text_elements.item(0)?.unwrap().append_child(
             &*toast_xml
-                .create_text_node(&FastHString::from(msg_title))?.unwrap()
+                .create_text_node(&FastHString::from(notification.title.as_str()))?.unwrap()
                 .query_interface::<IXmlNode>().unwrap(),
         )?;
         toast_text_elements.item(1)?.unwrap().append_child(
             &*toast_xml
-                .create_text_node(&FastHString::from(msg_body))?.unwrap()
+                .create_text_node(&FastHString::from(notification.body.as_str()))?.unwrap()
                 .query_interface::<IXmlNode>().unwrap(),
         )?;
 
-        let toast = ToastNotification::create_toast_notification(&*toast_xml)?;
-        ToastNotificationManager::create_toast_notifier_with_id(&FastHString::new(
-            "{1AC14E77-02E7-4E5D-B744-2EB1AE5198B7}\\WindowsPowerShell\\v1.0\\powershell.exe",
-        ))?.unwrap()
-            .show(&*toast)?;
-        Ok(())
+        if let Some(icon) = &notification.icon {
+            let image_elements =
+                toast_xml.get_elements_by_tag_name(&FastHString::new("image"))?.unwrap();
+            if let Some(image) = image_elements.item(0)? {
+                let image = image.query_interface::<IXmlElement>().unwrap();
+                image.set_attribute(&FastHString::new("src"), &FastHString::from(icon.as_str()))?;
+            }
+        }
+
+        if notification.timeout != Timeout::Default {
+            let toast_element = toast_xml
+                .get_elements_by_tag_name(&FastHString::new("toast"))?.unwrap()
+                .item(0)?.unwrap()
+                .query_interface::<IXmlElement>().unwrap();
+            let duration = match notification.timeout {
+                Timeout::Never => "long",
+                _ => "short",
+            };
+            toast_element.set_attribute(
+                &FastHString::new("duration"),
+                &FastHString::from(duration),
+            )?;
+        }
+
+        Ok(toast_xml)
     }
 }
 
 #[cfg(target_os = "macos")]
-struct MacOs;
+struct MacOs {
+    bundle_identifier: Option<String>,
+}
+
+/// Handle to a notification shown via [`MacOs`]. The bundled
+/// `mac_notification_sys` version has no API to mutate or close a
+/// delivered notification by identifier, so `update` re-sends it and
+/// `close` is a no-op.
+#[cfg(target_os = "macos")]
+struct MacOsHandle {
+    bundle_identifier: String,
+}
+
+#[cfg(target_os = "macos")]
+impl PlatformHandle for MacOsHandle {
+    fn update(&mut self, title: &str, body: &str) -> Result<(), ErrorRepr> {
+        mac_notification_sys::set_application(&self.bundle_identifier)?;
+        mac_notification_sys::send_notification(title, &None, body, &None)?;
+        Ok(())
+    }
+
+    fn close(self) -> Result<(), ErrorRepr> {
+        Ok(())
+    }
+}
 
 #[cfg(target_os = "macos")]
 impl Platform for MacOs {
+    type Handle = MacOsHandle;
+
     fn setup() -> Self {
-        MacOs
+        MacOs {
+            bundle_identifier: config().lock().unwrap().application.clone(),
+        }
     }
 
-    fn notify(msg_title: &str, msg_body: &str) -> Result<(), ErrorRepr> {
-        let bundle = mac_notification_sys::get_bundle_identifier("Script Editor").unwrap();
-        mac_notification_sys::set_application(&bundle).unwrap();
-        mac_notification_sys::send_notification(msg_title, &None, msg_body, &None).unwrap();
-        Ok(())
+    fn notify(&self, notification: &Notification) -> Result<MacOsHandle, ErrorRepr> {
+        let bundle = match &self.bundle_identifier {
+            Some(application) => mac_notification_sys::get_bundle_identifier(application)?,
+            None => mac_notification_sys::get_bundle_identifier("Script Editor")?,
+        };
+        mac_notification_sys::set_application(&bundle)?;
+        let sound = match &notification.sound {
+            Sound::Named(name) => Some(name.as_str()),
+            Sound::Default | Sound::Silent => None,
+        };
+        mac_notification_sys::send_notification(
+            &notification.title,
+            &None,
+            &notification.body,
+            &sound,
+        )?;
+        Ok(MacOsHandle {
+            bundle_identifier: bundle,
+        })
     }
+
+    fn notify_with_actions(
+        &self,
+        notification: &Notification,
+        _actions: &[Action],
+    ) -> Result<ActionResponse, ErrorRepr> {
+        // The bundled mac_notification_sys version doesn't expose a way to
+        // attach action buttons or read back which one was clicked, so the
+        // notification is shown plain and we report that upfront rather
+        // than faking a response the user never gave.
+        self.notify(notification)?;
+        Ok(ActionResponse::Unsupported)
+    }
+}
+
+#[cfg(target_os = "linux")]
+struct Linux {
+    application: Option<String>,
 }
 
 #[cfg(target_os = "linux")]
-struct Linux;
+struct LinuxHandle(notify_rust::NotificationHandle);
+
+#[cfg(target_os = "linux")]
+impl PlatformHandle for LinuxHandle {
+    fn update(&mut self, title: &str, body: &str) -> Result<(), ErrorRepr> {
+        self.0.summary(title);
+        self.0.body(body);
+        self.0.update()?;
+        Ok(())
+    }
+
+    fn close(self) -> Result<(), ErrorRepr> {
+        self.0.close();
+        Ok(())
+    }
+}
 
 #[cfg(target_os = "linux")]
 impl Platform for Linux {
+    type Handle = LinuxHandle;
+
     fn setup() -> Self {
-        Linux
+        Linux {
+            application: config().lock().unwrap().application.clone(),
+        }
     }
 
-    fn notify(msg_title: &str, msg_body: &str) -> Result<(), ErrorRepr> {
-        notify_rust::Notification::new()
-            .summary(msg_title)
-            .body(msg_body)
-            .show()?;
-        Ok(())
+    fn notify(&self, notification: &Notification) -> Result<LinuxHandle, ErrorRepr> {
+        Ok(LinuxHandle(self.build(notification).show()?))
+    }
+
+    fn notify_with_actions(
+        &self,
+        notification: &Notification,
+        actions: &[Action],
+    ) -> Result<ActionResponse, ErrorRepr> {
+        let mut n = self.build(notification);
+        for action in actions {
+            n.action(&action.id, &action.label);
+        }
+
+        let handle = n.show()?;
+        let response = std::cell::Cell::new(ActionResponse::Dismissed);
+        handle.wait_for_action(|action_id| {
+            response.set(match action_id {
+                "__closed" => ActionResponse::Dismissed,
+                "__timeout" => ActionResponse::TimedOut,
+                id => ActionResponse::Action(id.to_string()),
+            });
+        });
+        Ok(response.into_inner())
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Linux {
+    fn build(&self, notification: &Notification) -> notify_rust::Notification {
+        let mut n = notify_rust::Notification::new();
+        n.summary(&notification.title).body(&notification.body);
+
+        if let Some(application) = &self.application {
+            n.appname(application);
+        }
+
+        if let Some(icon) = &notification.icon {
+            n.icon(icon);
+        }
+
+        match &notification.sound {
+            Sound::Default => {}
+            Sound::Silent => {
+                n.hint(notify_rust::Hint::SuppressSound(true));
+            }
+            Sound::Named(name) => {
+                n.sound_name(name);
+            }
+        }
+
+        n.timeout(match notification.timeout {
+            Timeout::Default => notify_rust::Timeout::Default,
+            Timeout::Never => notify_rust::Timeout::Never,
+            Timeout::Milliseconds(ms) => notify_rust::Timeout::Milliseconds(ms),
+        });
+
+        n.hint(notify_rust::Hint::Urgency(match notification.urgency {
+            Urgency::Low => notify_rust::Urgency::Low,
+            Urgency::Normal => notify_rust::Urgency::Normal,
+            Urgency::Critical => notify_rust::Urgency::Critical,
+        }));
+
+        n
     }
 }
 
@@ -207,7 +794,60 @@ type CurrPlatform = MacOs;
 type CurrPlatform = Linux;
 
 pub fn notify(msg_title: &str, msg_body: &str) -> Result<(), Error> {
-    CurrPlatform::setup();
-    CurrPlatform::notify(msg_title, msg_body).map_err(Error)?;
+    NotificationBuilder::new(msg_title, msg_body).show()?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_message_recognizes_denied() {
+        assert_eq!(classify_message("Permission denied"), ErrorKind::PermissionDenied);
+        assert_eq!(classify_message("access DENIED"), ErrorKind::PermissionDenied);
+    }
+
+    #[test]
+    fn classify_message_recognizes_not_allowed() {
+        assert_eq!(
+            classify_message("Notifications are not allowed for this app"),
+            ErrorKind::PermissionDenied
+        );
+    }
+
+    #[test]
+    fn classify_message_falls_back_to_backend() {
+        assert_eq!(classify_message("D-Bus connection timed out"), ErrorKind::Backend);
+        assert_eq!(classify_message(""), ErrorKind::Backend);
+    }
+
+    #[test]
+    fn default_notification_uses_platform_defaults() {
+        let notification = Notification::default();
+        assert_eq!(notification.sound, Sound::Default);
+        assert_eq!(notification.timeout, Timeout::Default);
+        assert_eq!(notification.urgency, Urgency::Normal);
+        assert_eq!(notification.icon, None);
+    }
+
+    #[test]
+    fn builder_chaining_sets_all_fields() {
+        let builder = NotificationBuilder::new("Hello", "World")
+            .icon("/tmp/icon.png")
+            .sound(Sound::Named("bell".to_string()))
+            .timeout(Timeout::Milliseconds(500))
+            .urgency(Urgency::Critical)
+            .action("yes", "Yes");
+
+        assert_eq!(builder.notification.title, "Hello");
+        assert_eq!(builder.notification.body, "World");
+        assert_eq!(builder.notification.icon, Some("/tmp/icon.png".to_string()));
+        assert_eq!(builder.notification.sound, Sound::Named("bell".to_string()));
+        assert_eq!(builder.notification.timeout, Timeout::Milliseconds(500));
+        assert_eq!(builder.notification.urgency, Urgency::Critical);
+        assert_eq!(builder.actions.len(), 1);
+        assert_eq!(builder.actions[0].id, "yes");
+        assert_eq!(builder.actions[0].label, "Yes");
+    }
+}